@@ -1,12 +1,165 @@
 use tauri::{
   Manager,
+  menu::{Menu, MenuItem, PredefinedMenuItem},
   tray::{MouseButton, MouseButtonState, TrayIconEvent},
   WindowEvent,
+  LogicalSize,
   PhysicalPosition,
   PhysicalSize,
   Position,
+  Rect,
+  Size,
+  WebviewWindow,
 };
+use tauri_plugin_global_shortcut::{Code, GlobalShortcutExt, Modifiers, Shortcut, ShortcutState};
+use tauri_plugin_store::StoreExt;
 
+const SETTINGS_STORE: &str = "settings.json";
+const DEFAULT_POPOVER_WIDTH: f64 = 400.0;
+const DEFAULT_POPOVER_HEIGHT: f64 = 320.0;
+// Must match `trayIcon.id` in tauri.conf.json — Tauri defaults an
+// unspecified tray id to "main".
+const TRAY_ID: &str = "main";
+const DEFAULT_HOTKEY: &str = "Super+Shift+Space";
+
+
+/// Show/hide the popover, mirroring native menubar-popover behavior.
+///
+/// When `anchor` is the tray icon's rect, the popover is anchored relative
+/// to it — below and centered by default (macOS menu bar), flipping above
+/// when the tray sits in the bottom half of the screen and right-aligning
+/// when it sits against the right edge (Windows/Linux taskbars) — then
+/// clamped to stay fully within the monitor under the tray icon. When
+/// summoned without a tray rect (e.g. via the global hotkey), it falls back
+/// to centering on the monitor under the cursor.
+fn toggle_popover(window: &WebviewWindow, anchor: Option<Rect>) {
+  if window.is_visible().unwrap_or(false) {
+    window.hide().ok();
+    return;
+  }
+
+  // Use the window's actual (user-resized, store-persisted) size rather than
+  // a hard-coded constant, so a resized popover is positioned correctly too.
+  let gap: f64 = 8.0;
+  let scale_factor = window.scale_factor().unwrap_or(1.0);
+  let window_width = window
+    .outer_size()
+    .map(|s| s.width as f64)
+    .unwrap_or(DEFAULT_POPOVER_WIDTH);
+  let window_height = window
+    .outer_size()
+    .map(|s| s.height as f64)
+    .unwrap_or(DEFAULT_POPOVER_HEIGHT);
+
+  let (target_x, target_y) = if let Some(rect) = anchor {
+    let pos: PhysicalPosition<i32> = rect.position.to_physical(scale_factor);
+    let size: PhysicalSize<u32> = rect.size.to_physical(scale_factor);
+    let tray_center_x = pos.x as f64 + (size.width as f64 / 2.0);
+
+    let monitor = window
+      .available_monitors()
+      .ok()
+      .and_then(|monitors| {
+        monitors.into_iter().find(|m| {
+          let mp = m.position();
+          let ms = m.size();
+          pos.x >= mp.x
+            && pos.x < mp.x + ms.width as i32
+            && pos.y >= mp.y
+            && pos.y < mp.y + ms.height as i32
+        })
+      })
+      .or_else(|| window.current_monitor().ok().flatten());
+
+    let (x, y) = match &monitor {
+      Some(monitor) => {
+        let mp = monitor.position();
+        let ms = monitor.size();
+
+        // Taskbar/tray lives in the bottom half of the screen (Windows,
+        // Linux) — anchor the popover above the icon instead of below it,
+        // the way a macOS menu-bar icon would.
+        let tray_in_bottom_half = pos.y as f64 > mp.y as f64 + (ms.height as f64 / 2.0);
+        let y = if tray_in_bottom_half {
+          pos.y as f64 - window_height - gap
+        } else {
+          pos.y as f64 + size.height as f64 + gap
+        };
+
+        // Tray icon sits against the right edge (Windows/Linux bottom-right
+        // tray) — right-align the popover with it instead of centering.
+        let tray_near_right_edge = (pos.x as f64 + size.width as f64)
+          > mp.x as f64 + ms.width as f64 - window_width;
+        let x = if tray_near_right_edge {
+          pos.x as f64 + size.width as f64 - window_width
+        } else {
+          tray_center_x - (window_width / 2.0)
+        };
+
+        (x, y)
+      }
+      None => (
+        tray_center_x - (window_width / 2.0),
+        pos.y as f64 + size.height as f64 + gap,
+      ),
+    };
+
+    // Clamp to the monitor under the tray icon so the popover never
+    // renders partly or fully off-screen on smaller displays or corners.
+    if let Some(monitor) = monitor {
+      let mp = monitor.position();
+      let ms = monitor.size();
+
+      let min_x = mp.x as f64;
+      let max_x = (mp.x as f64 + ms.width as f64 - window_width).max(min_x);
+      let min_y = mp.y as f64;
+      let max_y = (mp.y as f64 + ms.height as f64 - window_height).max(min_y);
+
+      (x.clamp(min_x, max_x), y.clamp(min_y, max_y))
+    } else {
+      (x, y)
+    }
+  } else {
+    // No tray rect available (e.g. hotkey-triggered) — center on the
+    // monitor under the cursor instead.
+    let monitor = window
+      .cursor_position()
+      .ok()
+      .and_then(|cursor| {
+        window
+          .available_monitors()
+          .ok()?
+          .into_iter()
+          .find(|m| {
+            let pos = m.position();
+            let size = m.size();
+            (cursor.x as i32) >= pos.x
+              && (cursor.x as i32) < pos.x + size.width as i32
+              && (cursor.y as i32) >= pos.y
+              && (cursor.y as i32) < pos.y + size.height as i32
+          })
+      })
+      .or_else(|| window.current_monitor().ok().flatten());
+
+    if let Some(monitor) = monitor {
+      let pos = monitor.position();
+      let size = monitor.size();
+      let x = pos.x as f64 + (size.width as f64 / 2.0) - (window_width / 2.0);
+      let y = pos.y as f64 + (size.height as f64 / 2.0) - (window_height / 2.0);
+      (x, y)
+    } else {
+      (0.0, 0.0)
+    }
+  };
+
+  let _ = window.set_position(Position::Physical(PhysicalPosition::new(
+    target_x.round() as i32,
+    target_y.round() as i32,
+  )));
+
+  window.show().ok();
+  window.set_focus().ok();
+}
 
 #[cfg_attr(mobile, tauri::mobile_entry_point)]
 pub fn run() {
@@ -22,43 +175,34 @@ pub fn run() {
       } = event
       {
         if let Some(window) = app.get_webview_window("main") {
-          if window.is_visible().unwrap_or(false) {
-            window.hide().ok();
-          } else {
-            // Position the window near the tray icon (popover style).
-            // This uses the tray icon rect (screen coordinates) provided by Tauri.
-            let window_width: f64 = 400.0;
-            let gap: f64 = 8.0;
-
-            let scale_factor = window.scale_factor().unwrap_or(1.0);
-            let pos: PhysicalPosition<i32> = rect.position.to_physical(scale_factor);
-            let size: PhysicalSize<u32> = rect.size.to_physical(scale_factor);
-
-            let target_x = (pos.x as f64 + (size.width as f64 / 2.0)) - (window_width / 2.0);
-            let target_y = pos.y as f64 + size.height as f64 + gap;
-
-            let _ = window.set_position(Position::Physical(PhysicalPosition::new(
-              target_x.round() as i32,
-              target_y.round() as i32,
-            )));
-
-            #[cfg(target_os = "macos")]
-            {
-              window.show().ok();
-              window.set_focus().ok();
-            }
-
-            #[cfg(not(target_os = "macos"))]
-            {
-              window.show().ok();
-              window.set_focus().ok();
-            }
+          toggle_popover(&window, Some(rect));
+        }
+      }
+    })
+    .on_menu_event(|app, event| match event.id().as_ref() {
+      "show" => {
+        if let Some(window) = app.get_webview_window("main") {
+          if !window.is_visible().unwrap_or(false) {
+            toggle_popover(&window, None);
           }
         }
       }
+      "quit" => app.exit(0),
+      _ => {}
     })
     .plugin(tauri_plugin_clipboard_manager::init())
     .plugin(tauri_plugin_store::Builder::new().build())
+    .plugin(
+      tauri_plugin_global_shortcut::Builder::new()
+        .with_handler(|app, _shortcut, event| {
+          if event.state() == ShortcutState::Pressed {
+            if let Some(window) = app.get_webview_window("main") {
+              toggle_popover(&window, None);
+            }
+          }
+        })
+        .build(),
+    )
     .setup(|app| {
       #[cfg(target_os = "macos")]
       {
@@ -80,15 +224,103 @@ pub fn run() {
       // Without this, macOS may keep the window confined to the current Space.
       window.set_visible_on_all_workspaces(true).ok();
 
+      // Open the settings store up front — the hotkey, popover size, and
+      // pinned flag below are all read from it.
+      let store = app.store(SETTINGS_STORE)?;
+
+      // Register the global "summon popover" hotkey, since an
+      // Accessory-policy app has no dock icon to click. The combo is
+      // user-configurable via the `hotkey` store key (accelerator syntax,
+      // e.g. "Super+Shift+Space"), falling back to the default below.
+      let hotkey_str = store
+        .get("hotkey")
+        .and_then(|v| v.as_str().map(str::to_string))
+        .unwrap_or_else(|| DEFAULT_HOTKEY.to_string());
+      // Hard-coded last resort in case even `DEFAULT_HOTKEY` fails to parse
+      // (e.g. a future crate upgrade changes accepted modifier names) — a
+      // broken hotkey setting should never be able to crash startup.
+      let hardcoded_fallback = Shortcut::new(Some(Modifiers::SHIFT | Modifiers::SUPER), Code::Space);
+      let toggle_shortcut = hotkey_str.parse::<Shortcut>().unwrap_or_else(|err| {
+        log::warn!(
+          "invalid \"hotkey\" in settings store ({hotkey_str:?}): {err}; falling back to default"
+        );
+        DEFAULT_HOTKEY
+          .parse::<Shortcut>()
+          .unwrap_or(hardcoded_fallback)
+      });
+      if let Err(err) = app.global_shortcut().register(toggle_shortcut) {
+        log::warn!("failed to register global hotkey ({hotkey_str:?}): {err}");
+      }
 
+      // Attach a right-click context menu (Show, Quit) to the tray icon.
+      // A Preferences item will join it once there's a preferences window
+      // to open. This is wired separately from `on_tray_icon_event`'s
+      // left-click handler: showing the menu on left-click too is a known
+      // Tauri footgun that steals focus and forces a double-click on
+      // secondary windows, so the menu only opens on right-click and the
+      // popover toggle keeps working as before.
+      let show_item = MenuItem::with_id(app, "show", "Show Unscramm", true, None::<&str>)?;
+      let separator = PredefinedMenuItem::separator(app)?;
+      let quit_item = MenuItem::with_id(app, "quit", "Quit", true, None::<&str>)?;
+      let tray_menu = Menu::with_items(app, &[&show_item, &separator, &quit_item])?;
+      match app.tray_by_id(TRAY_ID) {
+        Some(tray) => {
+          tray.set_menu(Some(tray_menu))?;
+          tray.set_show_menu_on_left_click(false)?;
+        }
+        None => {
+          log::warn!(
+            "no tray icon with id \"{TRAY_ID}\" — tray context menu not attached; check the `id` (or default) of `trayIcon` in tauri.conf.json"
+          );
+        }
+      }
 
-      // Hide the window when it loses focus (click outside).
-      // This mimics native menubar popover behavior.
+      // Restore the user's persisted popover size (and "pinned" preference)
+      // from the store, falling back to the defaults on first launch.
+      let stored_width = store
+        .get("popoverWidth")
+        .and_then(|v| v.as_f64())
+        .unwrap_or(DEFAULT_POPOVER_WIDTH);
+      let stored_height = store
+        .get("popoverHeight")
+        .and_then(|v| v.as_f64())
+        .unwrap_or(DEFAULT_POPOVER_HEIGHT);
+      let pinned = store
+        .get("pinned")
+        .and_then(|v| v.as_bool())
+        .unwrap_or(false);
+      // Stored width/height are logical (DIP) units, matching the size
+      // Tauri windows are normally configured in — applying them as
+      // physical pixels would shrink the popover on any HiDPI display.
+      window
+        .set_size(Size::Logical(LogicalSize::new(stored_width, stored_height)))
+        .ok();
+
+      // Hide the window when it loses focus (click outside), unless the
+      // user has pinned it — a pinned popover stays open so it can be
+      // drag-resized or drag-repositioned. Also persist the size whenever
+      // the user resizes it, so it sticks across launches.
       let window_for_events = window.clone();
-      window.on_window_event(move |event| {
-        if let WindowEvent::Focused(false) = event {
-          window_for_events.hide().ok();
+      let app_handle = app.handle().clone();
+      window.on_window_event(move |event| match event {
+        WindowEvent::Focused(false) => {
+          if !pinned {
+            window_for_events.hide().ok();
+          }
+        }
+        WindowEvent::Resized(size) => {
+          // `size` is physical; convert back to logical before persisting
+          // so the restored size is correct even if the user's next
+          // launch happens on a monitor with a different scale factor.
+          let scale_factor = window_for_events.scale_factor().unwrap_or(1.0);
+          let logical = size.to_logical::<f64>(scale_factor);
+          if let Ok(store) = app_handle.store(SETTINGS_STORE) {
+            store.set("popoverWidth", logical.width);
+            store.set("popoverHeight", logical.height);
+            store.save().ok();
+          }
         }
+        _ => {}
       });
 
       // Initially hide the window (menu bar apps don't show on startup)